@@ -0,0 +1,57 @@
+//! Sample statistics used to summarize `bench_fn!` runs.
+
+/// Summary statistics over a set of per-iteration nanosecond timings.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub mad: f64,
+}
+
+impl BenchStats {
+    /// Computes summary statistics over `samples` (nanoseconds per iteration).
+    ///
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[f64]) -> BenchStats {
+        assert!(!samples.is_empty(), "bench_fn! collected no samples");
+
+        let mut sorted: Vec<f64> = samples.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let min = sorted[0];
+        let max = sorted[sorted.len() - 1];
+        let mean = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let median = percentile(&sorted, 0.5);
+
+        let variance = sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / sorted.len() as f64;
+        let std_dev = variance.sqrt();
+
+        let mut deviations: Vec<f64> = sorted.iter().map(|s| (s - median).abs()).collect();
+        deviations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mad = percentile(&deviations, 0.5);
+
+        BenchStats {
+            min,
+            max,
+            mean,
+            median,
+            std_dev,
+            mad,
+        }
+    }
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let idx = p * (sorted.len() - 1) as f64;
+    let lo = idx.floor() as usize;
+    let hi = idx.ceil() as usize;
+    if lo == hi {
+        sorted[lo]
+    } else {
+        let frac = idx - lo as f64;
+        sorted[lo] + (sorted[hi] - sorted[lo]) * frac
+    }
+}
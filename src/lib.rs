@@ -1,7 +1,31 @@
+pub mod bench;
+pub mod report;
+
 /// Creates a test module with multiple test cases.
 ///
 /// Provides a structured way to define test cases with shared variables and test case structures.
-/// Test cases can be marked with `skip` to be ignored during testing.
+/// Test cases can be marked with `skip` to be ignored during testing, and with
+/// `should_panic` (optionally `should_panic = "substring"`) to require the case to panic
+/// to pass; see `test_fn!` for the exact semantics.
+///
+/// A `fixture { .. }` block (plus optional `teardown |fixture| { .. }`) can follow the case
+/// list to share setup/cleanup across every case: the fixture block is evaluated fresh inside
+/// each generated test, its value is passed by reference as the closure's second argument
+/// (`|tc, fixture| { .. }`) and, since the fixture value lives in a binding this macro
+/// introduces, to the teardown closure's own parameter (named whatever the caller likes, here
+/// `fixture`) rather than as a bare identifier the teardown block would otherwise have no
+/// (hygienic) way to see. Teardown runs after the case body whether or not it panicked, and a
+/// teardown panic fails the test unless the body already panicked first. The fixture form
+/// accepts only `case(..)` and `skip case(..)` entries; `should_panic` markers are not
+/// supported there.
+///
+/// A `matrix { param: [v1, v2], .. }` section can be used instead of `cases{}[...]`
+/// to generate the full Cartesian product of the listed value lists, one test per
+/// combination, binding the tuple of values into the closure. Since stable Rust
+/// macros cannot synthesize a single identifier by concatenating tokens, each
+/// combination is identified by a nested module per parameter/value pair instead of
+/// one flattened test name: with parameters `p1, p2, ..`, the generated path is
+/// `name::p1::v1::p2::v2::.. ::case`, one pair of modules per parameter.
 ///
 /// # Example
 /// ```rust
@@ -10,7 +34,7 @@
 /// test_cases!(string_length =>
 ///     vars {
 ///         const TEST_STR: &'static str = "Hello";
-///     }, 
+///     },
 ///     cases {
 ///         struct TestCase {
 ///             input: &'static str,
@@ -25,20 +49,64 @@
 ///     }
 /// );
 /// ```
+///
+/// Matrix form, generating one test per `(level, unit)` combination under
+/// `dosage::level::LOW::unit::MG::case` and friends, reported as
+/// `dosage/level/LOW/unit/MG/case` and friends:
+///
+/// (`rust,ignore` below: like every `test_cases!` form this expands to a `#[cfg(test)]`
+/// module, which a doctest never compiles under `--cfg test` — see `mod tests` at the
+/// bottom of this file for the version that's actually exercised by `cargo test`.)
+/// ```rust,ignore
+/// use rtest::test_cases;
+///
+/// const LOW: &str = "low";
+/// const HIGH: &str = "high";
+/// const MG: &str = "mg";
+/// const ML: &str = "ml";
+///
+/// test_cases!(dosage =>
+///     matrix {
+///         level: [LOW, HIGH],
+///         unit: [MG, ML]
+///     } => |tc: (&str, &str)| {
+///         let (level, unit) = tc;
+///         assert!(!level.is_empty() && !unit.is_empty());
+///     }
+/// );
+/// ```
+///
+/// Fixture form, sharing setup/teardown across every case (see the same `rust,ignore`
+/// note above):
+/// ```rust,ignore
+/// use rtest::test_cases;
+///
+/// test_cases!(connection_handling =>
+///     vars {},
+///     cases {
+///         struct TestCase {
+///             add: u32,
+///             expected: u32,
+///         }
+///     }[
+///         case(one, TestCase { add: 1, expected: 1 }),
+///         case(two, TestCase { add: 2, expected: 2 }),
+///     ], fixture { 0u32 }, teardown |fixture| { assert_eq!(*fixture, 0); } => |tc: TestCase, fixture: &u32| {
+///         assert_eq!(tc.add, tc.expected);
+///         assert_eq!(*fixture, 0);
+///     }
+/// );
+/// ```
 #[macro_export]
 macro_rules! test_cases {
     ($name:ident => vars{
         $($init:item)*
     }, cases{$case:item}[
-        $(
-            skip case($skip_name:ident, $skip_case:expr)
-            $(, case($regular_name:ident, $regular_case:expr))?
-        ),+ $(,)?
+        $($rest:tt)*
     ] => $code:expr) => {
         #[cfg(test)]
         mod $name {
             use super::*;
-            use rtest::test_fn;
 
             $(
                 #[allow(unused_variables)]
@@ -46,32 +114,18 @@ macro_rules! test_cases {
             )*
             $case
 
-            $(
-                test_fn!(skip $skip_name, $name => {
-                    $code($skip_case)
-                });
-
-                $(
-                    test_fn!($regular_name, $name => {
-                        $code($regular_case)
-                    });
-                )?
-            )*
+            $crate::__rtest_cases!($name, $code ; $($rest)*);
         }
     };
 
     ($name:ident => vars{
         $($init:item)*
     }, cases{$case:item}[
-        $(
-            case($regular_name:ident, $regular_case:expr)
-            $(, skip case($skip_name:ident, $skip_case:expr))?
-        ),+ $(,)?
-    ] => $code:expr) => {
+        $($rest:tt)*
+    ], fixture $fixture:block $(, teardown |$tvar:ident| $teardown:block)? => $code:expr) => {
         #[cfg(test)]
         mod $name {
             use super::*;
-            use rtest::test_fn;
 
             $(
                 #[allow(unused_variables)]
@@ -79,18 +133,201 @@ macro_rules! test_cases {
             )*
             $case
 
+            $crate::__rtest_cases_fixture!($name, $code, $fixture $(, |$tvar| $teardown)? ; $($rest)*);
+        }
+    };
+
+    ($name:ident => matrix {
+        $($pname:ident : [$($val:ident),+ $(,)?]),+ $(,)?
+    } => $code:expr) => {
+        #[cfg(test)]
+        mod $name {
+            use super::*;
+
+            $crate::__rtest_matrix!(@expand $name, $code, () ; () ; $($pname : [$($val),+]),+);
+        }
+    };
+}
+
+/// Case-list expansion used by the `cases{}[...]` form of `test_cases!`.
+///
+/// Munches one `[skip] [should_panic [= "msg"]] case(name, expr)` entry at a time so that
+/// the modifiers can be combined in any of the supported ways without a combinatorial
+/// blow-up of `test_cases!` arms.
+///
+/// Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rtest_cases {
+    ($name:ident, $code:expr ;) => {};
+
+    ($name:ident, $code:expr ;
+        skip should_panic = $msg:literal case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(skip should_panic = $msg $case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr ;
+        skip should_panic case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(skip should_panic $case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr ;
+        should_panic = $msg:literal case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(should_panic = $msg $case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr ;
+        should_panic case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(should_panic $case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr ;
+        skip case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(skip $case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr ;
+        case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!($case_name, stringify!($name) => {
+            $code($case_expr)
+        });
+        $crate::__rtest_cases!($name, $code ; $($($rest)*)?);
+    };
+}
+
+/// Case-list expansion used by the `fixture { .. }` form of `test_cases!`.
+///
+/// Builds a fresh fixture per generated test, runs the case body wrapped in
+/// `catch_unwind` so the optional teardown still runs after a panicking case, then
+/// resumes the body's panic (preferred) or the teardown's (if the body passed but
+/// teardown didn't) so either failure fails the test as usual.
+///
+/// The teardown closure's parameter is always the caller's own identifier (from
+/// `teardown |x| { .. }`), not a name this macro introduces: a block referencing a
+/// bare identifier this macro binds internally (e.g. a literal `let fixture = ..;`)
+/// can't see that binding across macro hygiene, since the block's tokens come from the
+/// call site and the `let`'s from this macro's definition.
+///
+/// Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rtest_cases_fixture {
+    ($name:ident, $code:expr, $fixture:block $(, |$tvar:ident| $teardown:block)? ;) => {};
+
+    ($name:ident, $code:expr, $fixture:block $(, |$tvar:ident| $teardown:block)? ;
+        skip case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!(skip $case_name, stringify!($name) => {});
+        $crate::__rtest_cases_fixture!($name, $code, $fixture $(, |$tvar| $teardown)? ; $($($rest)*)?);
+    };
+
+    ($name:ident, $code:expr, $fixture:block $(, |$tvar:ident| $teardown:block)? ;
+        case($case_name:ident, $case_expr:expr) $(, $($rest:tt)*)?
+    ) => {
+        $crate::test_fn!($case_name, stringify!($name) => {
+            let fixture = $fixture;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                $code($case_expr, &fixture)
+            }));
+            let teardown_result: std::thread::Result<()> = Ok(());
             $(
-                test_fn!($regular_name, $name => {
-                    $code($regular_case)
-                });
-
-                $(
-                    test_fn!(skip $skip_name, $name => {
-                        $code($skip_case)
-                    });
-                )?
-            )*
+                let $tvar = &fixture;
+                let teardown_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $teardown));
+            )?
+            match result {
+                Ok(v) => {
+                    if let Err(err) = teardown_result {
+                        std::panic::resume_unwind(err);
+                    }
+                    v
+                },
+                Err(err) => std::panic::resume_unwind(err),
+            }
+        });
+        $crate::__rtest_cases_fixture!($name, $code, $fixture $(, |$tvar| $teardown)? ; $($($rest)*)?);
+    };
+}
+
+/// Cartesian-product expansion used by the `matrix { .. }` form of `test_cases!`.
+///
+/// Peels one parameter, then one value of that parameter, at a time (via `@values`)
+/// instead of expanding a whole value list with `$(..)+` alongside the `$val`
+/// accumulator: `macro_rules!` forbids zipping two independently-counted
+/// repetitions (the accumulated `$val`s and the current parameter's `$v`s) inside
+/// the same repetition, so each value is handled by its own recursive call.
+///
+/// Alongside the values, `$seg` accumulates the flat `param, value, param, value, ..`
+/// ident list for the combination so far, so the leaf test is reported under its full
+/// `outer/param/value/..` path instead of every combination sharing the single name
+/// `outer/case`. It's threaded the same way as `$val` (re-matched whole, not zipped
+/// against `$v`), and only turned into a string at the leaf via one flat `concat!` over
+/// `stringify!` of each segment — never by splicing an already-captured `:expr`
+/// fragment into a fresh `concat!` call, which `concat!` (unlike `format!`) can't see
+/// through.
+///
+/// Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rtest_matrix {
+    // No parameters left: emit the leaf test with the accumulated values tuple,
+    // reported under the full accumulated parameter/value path.
+    (@expand $outer:ident, $code:expr, ($($val:expr),*) ; ($($seg:ident),*) ;) => {
+        $crate::test_fn!(case, concat!(stringify!($outer) $(, "/", stringify!($seg))*) => {
+            $code(($($val),*))
+        });
+    };
+
+    // At least one parameter remains: open one module for it and hand its value
+    // list off to `@values`.
+    (@expand $outer:ident, $code:expr, ($($val:expr),*) ; ($($seg:ident),*) ;
+        $pname:ident : [$($v:ident),+ $(,)?] $(, $($rest:tt)*)?
+    ) => {
+        #[allow(non_snake_case)]
+        mod $pname {
+            use super::*;
+
+            $crate::__rtest_matrix!(@values $outer, $code, ($($val),*) ; ($($seg),*) ; $pname ; [$($v),+] ; $($($rest)*)?);
+        }
+    };
+
+    // No values left for the current parameter.
+    (@values $outer:ident, $code:expr, ($($val:expr),*) ; ($($seg:ident),*) ; $pname:ident ; [] ; $($rest:tt)*) => {};
+
+    // Peel one value off the current parameter's list, nest a module for it and
+    // recurse into the remaining parameters, then move on to the parameter's
+    // remaining values.
+    (@values $outer:ident, $code:expr, ($($val:expr),*) ; ($($seg:ident),*) ; $pname:ident ;
+        [$v:ident $(, $vtail:ident)*] ; $($rest:tt)*
+    ) => {
+        #[allow(non_snake_case)]
+        mod $v {
+            use super::super::*;
+
+            $crate::__rtest_matrix!(@expand $outer, $code, ($($val,)* $v) ; ($($seg,)* $pname, $v) ; $($rest)*);
         }
+
+        $crate::__rtest_matrix!(@values $outer, $code, ($($val),*) ; ($($seg),*) ; $pname ; [$($vtail),*] ; $($rest)*);
     };
 }
 
@@ -99,6 +336,19 @@ macro_rules! test_cases {
 /// This macro provides a simple way to create test functions that include execution time logging.
 /// Tests can be marked with `skip` to be ignored during testing.
 ///
+/// Each run is also pushed into a process-global collector (see [`report`]); call
+/// [`report::flush_reports`] to write it out as JUnit XML / JSON once the suite is done.
+///
+/// A `timeout(duration)` budget can be attached (e.g. `timeout(std::time::Duration::from_millis(500))`)
+/// to run the body on a spawned thread and FAIL the test with a `--- TIMEOUT:` line instead of
+/// hanging the suite if it doesn't finish in time; since the body crosses a thread boundary it
+/// must be `Send + 'static`.
+///
+/// A test marked `should_panic` (optionally `should_panic = "substring"`) PASSes only if its
+/// body panics and, when a substring is given, the panic message (a `&str` or `String`
+/// payload) contains it; a body that returns normally, or panics with a message lacking the
+/// substring, FAILs the test. `skip should_panic` marks such a test as skipped.
+///
 /// # Example
 /// ```rust
 /// mod tests {
@@ -116,19 +366,120 @@ macro_rules! test_cases {
 /// ```
 #[macro_export]
 macro_rules! test_fn {
-    (skip $name:ident $(, $sup_name:ident)? => $code:block) => {
+    (skip should_panic $(= $msg:literal)? $name:ident $(, $sup_name:expr)? => $code:block) => {
         #[test]
         #[ignore]
-        fn $name() {}
+        fn $name() {
+            let fn_name = stringify!($name).to_string();
+            $(
+                let fn_name = format!("{}/{}", $sup_name, fn_name);
+            )?
+            $crate::report::record(fn_name, $crate::report::Status::Skip, std::time::Duration::default(), None);
+        }
     };
 
-    ($name:ident $(, $sup_name:ident)? => $code:block) => {
+    (should_panic $(= $msg:literal)? $name:ident $(, $sup_name:expr)? => $code:block) => {
         #[test]
         fn $name() {
-            let fn_name = match stringify!($($sup_name)?) {
-                "" => stringify!($name).to_string(),
-                _ => format!("{}/{}", stringify!($($sup_name)?), stringify!($name))
+            let fn_name = stringify!($name).to_string();
+            $(
+                let fn_name = format!("{}/{}", $sup_name, fn_name);
+            )?
+
+            let start = std::time::Instant::now();
+            println!("=== RUN  \t{}", fn_name);
+            match std::panic::catch_unwind(|| $code) {
+                Ok(_) => {
+                    let t = start.elapsed();
+                    println!("--- FAIL:\t{} ({}.{}) (did not panic)", fn_name, t.as_secs(), t.subsec_millis());
+                    $crate::report::record(fn_name.clone(), $crate::report::Status::Fail, t, Some("test did not panic".to_string()));
+                    panic!("test {} did not panic as expected by should_panic", fn_name);
+                },
+                Err(err) => {
+                    let t = start.elapsed();
+                    let message = err.downcast_ref::<&str>().map(|s| s.to_string())
+                        .or_else(|| err.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+
+                    $(
+                        if !message.contains($msg) {
+                            println!("--- FAIL:\t{} ({}.{}) (panic message {:?} does not contain {:?})", fn_name, t.as_secs(), t.subsec_millis(), message, $msg);
+                            $crate::report::record(fn_name.clone(), $crate::report::Status::Fail, t, Some(format!("panic message {:?} does not contain {:?}", message, $msg)));
+                            panic!("test {} panicked with {:?}, expected message containing {:?}", fn_name, message, $msg);
+                        }
+                    )?
+
+                    println!("--- PASS:\t{} ({}.{})", fn_name, t.as_secs(), t.subsec_millis());
+                    $crate::report::record(fn_name, $crate::report::Status::Pass, t, None);
+                }
             };
+        }
+    };
+
+    (timeout($dur:expr) $name:ident $(, $sup_name:expr)? => $code:block) => {
+        #[test]
+        fn $name() {
+            let fn_name = stringify!($name).to_string();
+            $(
+                let fn_name = format!("{}/{}", $sup_name, fn_name);
+            )?
+            let budget: std::time::Duration = $dur;
+
+            let start = std::time::Instant::now();
+            println!("=== RUN  \t{}", fn_name);
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let handle = std::thread::spawn(move || {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $code));
+                let _ = tx.send(result);
+            });
+
+            match rx.recv_timeout(budget) {
+                Ok(Ok(_)) => {
+                    let t = start.elapsed();
+                    println!("--- PASS:\t{} ({}.{})", fn_name, t.as_secs(), t.subsec_millis());
+                    $crate::report::record(fn_name, $crate::report::Status::Pass, t, None);
+                    let _ = handle.join();
+                },
+                Ok(Err(err)) => {
+                    let t = start.elapsed();
+                    println!("--- FAIL:\t{} ({}.{})", fn_name, t.as_secs(), t.subsec_millis());
+                    let message = err.downcast_ref::<&str>().map(|s| s.to_string())
+                        .or_else(|| err.downcast_ref::<String>().cloned());
+                    $crate::report::record(fn_name.clone(), $crate::report::Status::Fail, t, message);
+                    let _ = handle.join();
+                    std::panic::resume_unwind(err);
+                },
+                Err(_) => {
+                    println!("--- TIMEOUT:\t{} (exceeded {:?})", fn_name, budget);
+                    $crate::report::record(fn_name.clone(), $crate::report::Status::Fail, budget, Some(format!("exceeded timeout budget of {:?}", budget)));
+                    // The worker thread is still running; drop the handle to detach it
+                    // rather than blocking the suite on a test that may never return.
+                    panic!("test {} did not finish within {:?}", fn_name, budget);
+                },
+            }
+        }
+    };
+
+    (skip $name:ident $(, $sup_name:expr)? => $code:block) => {
+        #[test]
+        #[ignore]
+        fn $name() {
+            let fn_name = stringify!($name).to_string();
+            $(
+                let fn_name = format!("{}/{}", $sup_name, fn_name);
+            )?
+            $crate::report::record(fn_name, $crate::report::Status::Skip, std::time::Duration::default(), None);
+        }
+    };
+
+    ($name:ident $(, $sup_name:expr)? => $code:block) => {
+        #[test]
+        fn $name() {
+            let fn_name = stringify!($name).to_string();
+            $(
+                let fn_name = format!("{}/{}", $sup_name, fn_name);
+            )?
 
             let start = std::time::Instant::now();
             println!("=== RUN  \t{}", fn_name);
@@ -136,13 +487,118 @@ macro_rules! test_fn {
                 Ok(_) => {
                     let t = start.elapsed();
                     println!("--- PASS:\t{} ({}.{})", fn_name, t.as_secs(), t.subsec_millis());
+                    $crate::report::record(fn_name, $crate::report::Status::Pass, t, None);
                 },
                 Err(err) => {
                     let t = start.elapsed();
                     println!("--- FAIL:\t{} ({}.{})", fn_name, t.as_secs(), t.subsec_millis());
+                    let message = err.downcast_ref::<&str>().map(|s| s.to_string())
+                        .or_else(|| err.downcast_ref::<String>().cloned());
+                    $crate::report::record(fn_name.clone(), $crate::report::Status::Fail, t, message);
                     std::panic::resume_unwind(err);
                 }
             };
         }
     };
 }
+
+/// Creates a microbenchmark function reporting iteration-time statistics.
+///
+/// The body is run through a warmup loop that geometrically grows the iteration
+/// count until a batch takes at least ~100ms, then ~50 such batches are timed and
+/// summarized as min/max/mean/median/standard-deviation/MAD, mirroring `cargo bench`'s
+/// terse `ns/iter (+/- ...)` output.
+///
+/// # Example
+/// ```rust
+/// mod benches {
+///     use rtest::bench_fn;
+///
+///     bench_fn!(vec_push => {
+///         let mut v = Vec::with_capacity(8);
+///         v.push(1);
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! bench_fn {
+    ($name:ident => $code:block) => {
+        #[test]
+        fn $name() {
+            let fn_name = stringify!($name);
+            println!("=== RUN  \t{}", fn_name);
+
+            let mut iters: u64 = 1;
+            loop {
+                let start = std::time::Instant::now();
+                for _ in 0..iters {
+                    $code;
+                }
+                if start.elapsed() >= std::time::Duration::from_millis(100) || iters >= 1 << 30 {
+                    break;
+                }
+                iters *= 2;
+            }
+
+            const BATCHES: usize = 50;
+            let mut samples: Vec<f64> = Vec::with_capacity(BATCHES);
+            for _ in 0..BATCHES {
+                let start = std::time::Instant::now();
+                for _ in 0..iters {
+                    $code;
+                }
+                samples.push(start.elapsed().as_nanos() as f64 / iters as f64);
+            }
+
+            let stats = $crate::bench::BenchStats::from_samples(&samples);
+            println!(
+                "--- BENCH:\t{} {:.0} ns/iter (+/- {:.0})",
+                fn_name, stats.mean, stats.mad,
+            );
+            println!(
+                "    \tmin {:.0} / max {:.0} / median {:.0} / std dev {:.0} ns/iter",
+                stats.min, stats.max, stats.median, stats.std_dev,
+            );
+        }
+    };
+}
+
+// These exercise the `matrix` and `fixture`/`teardown` forms of `test_cases!` for
+// real, under `cargo test`: the doctests above can't, since every `test_cases!` form
+// expands to a `#[cfg(test)] mod`, and doctests aren't compiled with `--cfg test` —
+// that module (and whatever bug is inside it) simply disappears rather than failing.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOW: &str = "low";
+    const HIGH: &str = "high";
+    const MG: &str = "mg";
+    const ML: &str = "ml";
+
+    test_cases!(dosage =>
+        matrix {
+            level: [LOW, HIGH],
+            unit: [MG, ML]
+        } => |tc: (&str, &str)| {
+            let (level, unit) = tc;
+            assert!(!level.is_empty() && !unit.is_empty());
+        }
+    );
+
+    test_cases!(connection_handling =>
+        vars {},
+        cases {
+            struct TestCase {
+                add: u32,
+                expected: u32,
+            }
+        }[
+            case(one, TestCase { add: 1, expected: 1 }),
+            case(two, TestCase { add: 2, expected: 2 }),
+        ], fixture { 0u32 }, teardown |fixture| { assert_eq!(*fixture, 0); } => |tc: TestCase, fixture: &u32| {
+            assert_eq!(tc.add, tc.expected);
+            assert_eq!(*fixture, 0);
+        }
+    );
+}
@@ -0,0 +1,154 @@
+//! Structured test reporting.
+//!
+//! Every `test_fn!`-generated test pushes a [`TestRecord`] into a process-global
+//! collector as it finishes. Call [`flush_junit`] / [`flush_json`] (e.g. from a
+//! `#[ctor]`-annotated function, or the last test in your suite) to serialize the
+//! collected records to disk.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Outcome of a single test, as recorded for reporting purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Skip,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Skip => "skip",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A single test's outcome, as recorded for reporting purposes.
+#[derive(Debug, Clone)]
+pub struct TestRecord {
+    pub name: String,
+    pub status: Status,
+    pub duration: Duration,
+    pub message: Option<String>,
+}
+
+fn records() -> &'static Mutex<Vec<TestRecord>> {
+    static RECORDS: OnceLock<Mutex<Vec<TestRecord>>> = OnceLock::new();
+    RECORDS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Pushes a test outcome into the global collector.
+///
+/// Called from the code generated by `test_fn!`; not normally called directly.
+pub fn record(name: impl Into<String>, status: Status, duration: Duration, message: Option<String>) {
+    records().lock().unwrap().push(TestRecord {
+        name: name.into(),
+        status,
+        duration,
+        message,
+    });
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Writes the collected records as a JUnit-style `<testsuite>` XML document.
+pub fn flush_junit(path: impl AsRef<Path>) -> io::Result<()> {
+    let recs = records().lock().unwrap();
+    let failures = recs.iter().filter(|r| r.status == Status::Fail).count();
+    let skipped = recs.iter().filter(|r| r.status == Status::Skip).count();
+    let total_time: f64 = recs.iter().map(|r| r.duration.as_secs_f64()).sum();
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuite name=\"rtest\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+        recs.len(),
+        failures,
+        skipped,
+        total_time,
+    ));
+    for r in recs.iter() {
+        out.push_str(&format!(
+            "  <testcase name=\"{}\" time=\"{:.3}\"",
+            escape_xml(&r.name),
+            r.duration.as_secs_f64(),
+        ));
+        match r.status {
+            Status::Pass => out.push_str("/>\n"),
+            Status::Skip => out.push_str(">\n    <skipped/>\n  </testcase>\n"),
+            Status::Fail => {
+                out.push_str(">\n");
+                let message = r.message.as_deref().unwrap_or("test failed");
+                out.push_str(&format!(
+                    "    <failure message=\"{}\"/>\n",
+                    escape_xml(message),
+                ));
+                out.push_str("  </testcase>\n");
+            }
+        }
+    }
+    out.push_str("</testsuite>\n");
+
+    fs::write(path, out)
+}
+
+/// Writes the collected records as a line-delimited JSON event stream.
+pub fn flush_json(path: impl AsRef<Path>) -> io::Result<()> {
+    let recs = records().lock().unwrap();
+    let mut file = fs::File::create(path)?;
+    for r in recs.iter() {
+        let message = match &r.message {
+            Some(m) => format!("\"{}\"", escape_json(m)),
+            None => "null".to_string(),
+        };
+        writeln!(
+            file,
+            "{{\"name\":\"{}\",\"status\":\"{}\",\"duration_ms\":{},\"message\":{}}}",
+            escape_json(&r.name),
+            r.status,
+            r.duration.as_millis(),
+            message,
+        )?;
+    }
+    Ok(())
+}
+
+/// Flushes the collected records to the paths named by `RTEST_JUNIT_PATH` and
+/// `RTEST_JSON_PATH`, skipping whichever env var is unset.
+pub fn flush_reports() -> io::Result<()> {
+    if let Ok(path) = std::env::var("RTEST_JUNIT_PATH") {
+        flush_junit(path)?;
+    }
+    if let Ok(path) = std::env::var("RTEST_JSON_PATH") {
+        flush_json(path)?;
+    }
+    Ok(())
+}